@@ -1,4 +1,5 @@
 use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 use std::vec;
 
 /// A trait to unify FnMut for GroupByLazy with the chunk key in ChunksLazy
@@ -19,6 +20,7 @@ impl<'a, A, K, F: ?Sized> KeyFunction<A> for F
 
 
 /// ChunkIndex acts like the grouping key function for ChunksLazy
+#[derive(Clone)]
 struct ChunkIndex {
     size: usize,
     index: usize,
@@ -70,6 +72,8 @@ struct GroupInner<K, I, F>
     buffer: Vec<vec::IntoIter<I::Item>>,
     /// index of last group iter that was dropped, usize::MAX == none
     dropped_group: usize,
+    /// total number of elements pulled out of `iter` so far
+    count: usize,
 }
 
 impl<K, I, F> GroupInner<K, I, F>
@@ -141,7 +145,7 @@ impl<K, I, F> GroupInner<K, I, F>
         debug_assert!(!self.done);
         match self.iter.next() {
             None => { self.done = true; None }
-            otherwise => otherwise,
+            Some(elt) => { self.count += 1; Some(elt) }
         }
     }
 
@@ -266,6 +270,42 @@ impl<K, I, F> GroupInner<K, I, F>
             self.dropped_group = client;
         }
     }
+
+    /// Bounds on the total number of elements `iter` will ever produce:
+    /// the elements already pulled out (`self.count`) plus whatever bounds
+    /// `iter`'s own `size_hint` reports for what's left. Exact whenever
+    /// `iter`'s `size_hint` is exact, e.g. when `I: ExactSizeIterator`.
+    fn total_len_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (self.count + lo, hi.map(|hi| self.count + hi))
+    }
+}
+
+impl<K, I, F> Clone for GroupInner<K, I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          K: Clone,
+          F: Clone,
+{
+    fn clone(&self) -> Self {
+        GroupInner {
+            key: self.key.clone(),
+            iter: self.iter.clone(),
+            current_key: self.current_key.clone(),
+            current_elt: self.current_elt.clone(),
+            done: self.done,
+            top: self.top,
+            bot: self.bot,
+            bufbot: self.bufbot,
+            // deep-copy each buffered group's remaining elements so the
+            // clone does not share state with the original
+            buffer: self.buffer.iter()
+                        .map(|queue| queue.as_slice().to_vec().into_iter())
+                        .collect(),
+            dropped_group: self.dropped_group,
+            count: self.count,
+        }
+    }
 }
 
 /// `GroupByLazy` is the storage for the lazy grouping operation.
@@ -307,6 +347,7 @@ pub fn new<K, J, F>(iter: J, f: F) -> GroupByLazy<K, J::IntoIter, F>
             bufbot: 0,
             buffer: Vec::new(),
             dropped_group: !0,
+            count: 0,
         }),
         index: Cell::new(0),
     }
@@ -329,6 +370,20 @@ impl<K, I, F> GroupByLazy<K, I, F>
     }
 }
 
+impl<K, I, F> Clone for GroupByLazy<K, I, F>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+          K: Clone,
+          F: Clone,
+{
+    fn clone(&self) -> Self {
+        GroupByLazy {
+            inner: RefCell::new(self.inner.borrow().clone()),
+            index: Cell::new(self.index.get()),
+        }
+    }
+}
+
 impl<'a, K, I, F> IntoIterator for &'a GroupByLazy<K, I, F>
     where I: Iterator,
           I::Item: 'a,
@@ -436,6 +491,7 @@ pub fn new_chunks<J>(iter: J, size: usize) -> ChunksLazy<J::IntoIter>
             bufbot: 0,
             buffer: Vec::new(),
             dropped_group: !0,
+            count: 0,
         }),
         index: Cell::new(0),
     }
@@ -477,6 +533,52 @@ impl<I> ChunksLazy<I>
     fn drop_group(&self, client: usize) {
         self.inner.borrow_mut().drop_group(client)
     }
+
+    /// Bounds on the number of chunks from `from` (inclusive) onward.
+    fn chunks_size_hint(&self, from: usize) -> (usize, Option<usize>) {
+        let inner = self.inner.borrow();
+        let size = inner.key.size;
+        let (lo, hi) = inner.total_len_hint();
+        let lo = (lo + size - 1) / size;
+        let hi = hi.map(|hi| (hi + size - 1) / size);
+        (lo.saturating_sub(from), hi.map(|hi| hi.saturating_sub(from)))
+    }
+
+    /// Bounds on the number of elements left to produce for chunk `client`,
+    /// given whether its `Chunk` still holds an unread first element.
+    fn chunk_size_hint(&self, client: usize, has_first: bool) -> (usize, Option<usize>) {
+        let inner = self.inner.borrow();
+        let size = inner.key.size;
+        let start = client.saturating_mul(size);
+        let (total_lo, total_hi) = inner.total_len_hint();
+        let chunk_len = |total: usize| if start >= total { 0 } else { (total - start).min(size) };
+        let pulled = if inner.count <= start { 0 } else { (inner.count - start).min(size) };
+        let buffered = if client < inner.bot {
+            0
+        } else if client < inner.top ||
+            (client == inner.top && inner.buffer.len() > inner.top - inner.bufbot)
+        {
+            inner.buffer.get(client - inner.bufbot).map_or(0, |queue| queue.len())
+        } else {
+            0
+        };
+        let extra = buffered + if has_first { 1 } else { 0 };
+        let lo = chunk_len(total_lo).saturating_sub(pulled) + extra;
+        let hi = total_hi.map(|hi| chunk_len(hi).saturating_sub(pulled) + extra);
+        (lo, hi)
+    }
+}
+
+impl<I> Clone for ChunksLazy<I>
+    where I: Iterator + Clone,
+          I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        ChunksLazy {
+            inner: RefCell::new(self.inner.borrow().clone()),
+            index: Cell::new(self.index.get()),
+        }
+    }
 }
 
 impl<'a, I> IntoIterator for &'a ChunksLazy<I>
@@ -493,6 +595,42 @@ impl<'a, I> IntoIterator for &'a ChunksLazy<I>
     }
 }
 
+#[cfg(test)]
+mod clone_tests {
+    use super::{new, new_chunks};
+
+    #[test]
+    fn group_by_lazy_clone_preserves_buffered_state() {
+        let gl = new(vec![1, 1, 2, 2, 3, 3].into_iter(), |x: &i32| *x);
+        // drive group 1 ahead of group 0, forcing the rest of group 0 to
+        // be buffered rather than read straight off the source iterator
+        assert_eq!(gl.step(0), Some(1));
+        assert_eq!(gl.step(1), Some(2));
+
+        let clone = gl.clone();
+
+        // the buffered remainder of group 0 must be cloned too, and the
+        // two copies must not share state from here on
+        assert_eq!(gl.step(0), Some(1));
+        assert_eq!(clone.step(0), Some(1));
+        assert_eq!(gl.step(0), None);
+        assert_eq!(clone.step(0), None);
+    }
+
+    #[test]
+    fn chunks_lazy_clone_preserves_buffered_state() {
+        let cl = new_chunks(0..6, 2);
+        assert_eq!(cl.step(0), Some(0));
+        assert_eq!(cl.step(1), Some(2));
+
+        let clone = cl.clone();
+
+        assert_eq!(cl.step(0), Some(1));
+        assert_eq!(clone.step(0), Some(1));
+        assert_eq!(cl.step(0), None);
+        assert_eq!(clone.step(0), None);
+    }
+}
 
 /// An iterator that yields the Chunk iterators.
 ///
@@ -525,6 +663,49 @@ impl<'a, I> Iterator for Chunks<'a, I>
             }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.parent.chunks_size_hint(self.parent.index.get())
+    }
+}
+
+impl<'a, I> ExactSizeIterator for Chunks<'a, I>
+    where I: ExactSizeIterator,
+          I::Item: 'a,
+{}
+
+#[cfg(test)]
+mod size_hint_tests {
+    use super::new_chunks;
+
+    #[test]
+    fn chunks_exact_len_tracks_remaining_groups() {
+        let cl = new_chunks(vec![0, 1, 2, 3, 4, 5, 6].into_iter(), 3);
+        let mut chunks = (&cl).into_iter();
+        assert_eq!(chunks.len(), 3); // [0,1,2], [3,4,5], [6]
+        assert_eq!(chunks.next().unwrap().count(), 3);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.next().unwrap().count(), 3);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks.next().unwrap().count(), 1);
+        assert_eq!(chunks.len(), 0);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn chunk_exact_len_tracks_remaining_elements() {
+        let cl = new_chunks(vec![0, 1, 2, 3].into_iter(), 3);
+        let mut chunks = (&cl).into_iter();
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.len(), 3);
+        assert_eq!(chunk.next(), Some(0));
+        assert_eq!(chunk.len(), 2);
+        assert_eq!(chunk.next(), Some(1));
+        assert_eq!(chunk.len(), 1);
+        assert_eq!(chunk.next(), Some(2));
+        assert_eq!(chunk.len(), 0);
+        assert_eq!(chunk.next(), None);
+    }
 }
 
 /// An iterator for the elements in a single chunk.
@@ -560,4 +741,280 @@ impl<'a, I> Iterator for Chunk<'a, I>
         }
         self.parent.step(self.index)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.parent.chunk_size_hint(self.index, self.first.is_some())
+    }
+}
+
+impl<'a, I> ExactSizeIterator for Chunk<'a, I>
+    where I: ExactSizeIterator,
+          I::Item: 'a,
+{}
+
+///// FoldChunks /////
+
+/// Create a new `FoldChunks`
+pub fn new_fold_chunks<I, T, F>(iter: I, size: usize, init: T, fold: F) -> FoldChunks<I, T, F>
+    where I: Iterator,
+          T: Clone,
+          F: FnMut(T, I::Item) -> T,
+{
+    assert!(size != 0);
+    FoldChunks {
+        iter: iter,
+        size: size,
+        init: init,
+        fold: fold,
+    }
+}
+
+/// An iterator that groups the items of `I` into chunks of (at most) a fixed
+/// size and folds each chunk down to a single value as it is produced,
+/// without buffering the chunk's elements.
+///
+/// See [`.fold_chunks()`](trait.Itertools.html#method.fold_chunks) for more
+/// information.
+pub struct FoldChunks<I, T, F> {
+    iter: I,
+    size: usize,
+    init: T,
+    fold: F,
+}
+
+impl<I, T, F> Iterator for FoldChunks<I, T, F>
+    where I: Iterator,
+          T: Clone,
+          F: FnMut(T, I::Item) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut acc = match self.iter.next() {
+            None => return None,
+            Some(elt) => (self.fold)(self.init.clone(), elt),
+        };
+        for _ in 1..self.size {
+            match self.iter.next() {
+                None => break,
+                Some(elt) => acc = (self.fold)(acc, elt),
+            }
+        }
+        Some(acc)
+    }
+}
+
+/// Create a new `FoldChunksWith`
+pub fn new_fold_chunks_with<I, S, T, F>(iter: I, size: usize, seed: S, fold: F) -> FoldChunksWith<I, S, F>
+    where I: Iterator,
+          S: FnMut() -> T,
+          F: FnMut(T, I::Item) -> T,
+{
+    assert!(size != 0);
+    FoldChunksWith {
+        iter: iter,
+        size: size,
+        seed: seed,
+        fold: fold,
+    }
+}
+
+/// Like [`FoldChunks`], but calls a `seed` closure to produce the initial
+/// accumulator of each chunk instead of requiring the accumulator type to be
+/// `Clone`.
+///
+/// See [`.fold_chunks_with()`](trait.Itertools.html#method.fold_chunks_with)
+/// for more information.
+pub struct FoldChunksWith<I, S, F> {
+    iter: I,
+    size: usize,
+    seed: S,
+    fold: F,
+}
+
+impl<I, S, T, F> Iterator for FoldChunksWith<I, S, F>
+    where I: Iterator,
+          S: FnMut() -> T,
+          F: FnMut(T, I::Item) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut acc = match self.iter.next() {
+            None => return None,
+            Some(elt) => (self.fold)((self.seed)(), elt),
+        };
+        for _ in 1..self.size {
+            match self.iter.next() {
+                None => break,
+                Some(elt) => acc = (self.fold)(acc, elt),
+            }
+        }
+        Some(acc)
+    }
+}
+
+#[cfg(test)]
+mod fold_chunks_tests {
+    use super::{new_fold_chunks, new_fold_chunks_with};
+
+    #[test]
+    fn fold_chunks_sums_each_chunk() {
+        let sums: Vec<i32> = new_fold_chunks(0..10, 3, 0, |acc, x| acc + x).collect();
+        // [0,1,2], [3,4,5], [6,7,8], [9]
+        assert_eq!(sums, vec![3, 12, 21, 9]);
+    }
+
+    #[test]
+    fn fold_chunks_with_builds_fresh_accumulator_per_chunk() {
+        let chunks: Vec<Vec<i32>> =
+            new_fold_chunks_with(0..7, 3, Vec::new, |mut acc, x| { acc.push(x); acc }).collect();
+        assert_eq!(chunks, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+    }
+}
+
+///// ChunksStepped /////
+
+/// Create a new `ChunksStepped`
+pub fn new_chunks_stepped<J>(iter: J, size: usize, step: usize) -> ChunksStepped<J::IntoIter>
+    where J: IntoIterator,
+{
+    assert!(size != 0, "chunk size must be non-zero");
+    assert!(step != 0, "step must be non-zero");
+    ChunksStepped {
+        iter: iter.into_iter(),
+        size: size,
+        step: step,
+        ring: VecDeque::with_capacity(size),
+        started: false,
+        exhausted: false,
+        done: false,
+    }
+}
+
+/// An iterator that yields overlapping or strided windows of up to `size`
+/// consecutive elements, starting a new window every `step` elements.
+///
+/// When `step < size` the windows overlap, so a source element can belong
+/// to more than one window at once; when `step > size` elements are
+/// skipped between windows. A window that reaches past the end of the
+/// source is shorter than `size`; when `step < size` there can be several
+/// such tail windows, one for each start position before the end.
+///
+/// Unlike [`ChunksLazy`], whose groups partition the source so each
+/// element is handed to exactly one group's lazy sub-iterator, each window
+/// here is materialized eagerly as a `Vec`. `size` and `step` are runtime
+/// values, so the `I::Item: Clone` bound has to cover the worst case (an
+/// overlapping window, where the same element appears in more than one
+/// yielded `Vec`) even though it isn't needed at runtime: when `step >=
+/// size` windows never overlap, and `next()` moves elements out of its
+/// internal buffer instead of cloning them.
+///
+/// See [`.chunks_stepped()`](trait.Itertools.html#method.chunks_stepped)
+/// for more information.
+pub struct ChunksStepped<I>
+    where I: Iterator,
+{
+    iter: I,
+    size: usize,
+    step: usize,
+    /// the up-to-`size` most recently seen elements, making up the window
+    /// that is about to be (or was just) yielded
+    ring: VecDeque<I::Item>,
+    /// whether the first window has already been produced
+    started: bool,
+    /// whether `iter` has been fully drained; once this is set, `next()`
+    /// only keeps shrinking `ring` by `step` each call until it runs dry,
+    /// so every window whose start index is `< len` still gets yielded
+    exhausted: bool,
+    done: bool,
+}
+
+impl<I> Iterator for ChunksStepped<I>
+    where I: Iterator,
+          I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.started {
+            if self.step >= self.size {
+                // windows don't overlap (and may skip elements entirely);
+                // nothing from the previous window carries over
+                self.ring.clear();
+                if !self.exhausted {
+                    for _ in 0..(self.step - self.size) {
+                        if self.iter.next().is_none() {
+                            self.exhausted = true;
+                            break;
+                        }
+                    }
+                }
+            } else {
+                // windows overlap; drop the elements the next window has
+                // already moved past. Once `iter` is exhausted this is the
+                // only way `ring` shrinks, producing a run of ever-smaller
+                // tail windows instead of stopping after the first one.
+                let drop = if self.step < self.ring.len() { self.step } else { self.ring.len() };
+                for _ in 0..drop {
+                    self.ring.pop_front();
+                }
+            }
+        }
+        self.started = true;
+        if !self.exhausted {
+            while self.ring.len() < self.size {
+                match self.iter.next() {
+                    Some(elt) => self.ring.push_back(elt),
+                    None => { self.exhausted = true; break; }
+                }
+            }
+        }
+        if self.ring.is_empty() {
+            self.done = true;
+            return None;
+        }
+        Some(if self.step >= self.size {
+            // no element belongs to more than one window here, so the
+            // whole window can be moved out of the ring instead of cloned
+            self.ring.drain(..).collect()
+        } else {
+            self.ring.iter().cloned().collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod chunks_stepped_tests {
+    use super::new_chunks_stepped;
+
+    #[test]
+    fn emits_every_valid_tail_window() {
+        // size=4, step=1 over 0..5: every start index < len must get a
+        // window, shrinking once the source runs out, not just the first
+        // short tail.
+        let windows: Vec<Vec<i32>> = new_chunks_stepped(0..5, 4, 1).collect();
+        assert_eq!(windows, vec![
+            vec![0, 1, 2, 3],
+            vec![1, 2, 3, 4],
+            vec![2, 3, 4],
+            vec![3, 4],
+            vec![4],
+        ]);
+    }
+
+    #[test]
+    fn non_overlap_skips_between_windows() {
+        let windows: Vec<Vec<i32>> = new_chunks_stepped(0..7, 2, 3).collect();
+        assert_eq!(windows, vec![vec![0, 1], vec![3, 4], vec![6]]);
+    }
+
+    #[test]
+    fn non_overlap_stops_exactly_on_boundary() {
+        let windows: Vec<Vec<i32>> = new_chunks_stepped(0..6, 2, 3).collect();
+        assert_eq!(windows, vec![vec![0, 1], vec![3, 4]]);
+    }
 }
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::cmp::Ordering;
+use std::hash::Hash;
+use std::ops::{Add, Mul};
+
+/// Create a new `GroupingMap` from `iter`
+pub fn new<I, K, V>(iter: I) -> GroupingMap<I>
+    where I: Iterator<Item = (K, V)>,
+          K: Hash + Eq,
+{
+    GroupingMap { iter: iter }
+}
+
+/// Create a new `GroupingMap` from `iter`, using `key_mapper` to extract each
+/// element's key.
+pub fn new_by<I, K, F>(iter: I, key_mapper: F) -> GroupingMap<GroupingMapBy<I, F>>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+          K: Hash + Eq,
+{
+    GroupingMap { iter: GroupingMapBy { iter: iter, key_mapper: key_mapper } }
+}
+
+/// An iterator that maps each of its source iterator's items to a `(key, item)`
+/// pair, using a key-computing closure.
+///
+/// See [`.into_grouping_map_by()`](trait.Itertools.html#method.into_grouping_map_by)
+/// for more information.
+pub struct GroupingMapBy<I, F> {
+    iter: I,
+    key_mapper: F,
+}
+
+impl<I, K, F> Iterator for GroupingMapBy<I, F>
+    where I: Iterator,
+          F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|val| {
+            let key = (self.key_mapper)(&val);
+            (key, val)
+        })
+    }
+}
+
+/// `GroupingMap` is an intermediate struct for efficient group-and-fold operations.
+/// It groups elements of the source iterator by key and folds the elements of
+/// each group with a user-supplied operation, eagerly consuming the whole
+/// source and returning a `HashMap` of the results.
+///
+/// Unlike [`.group_by()`](trait.Itertools.html#method.group_by), the groups
+/// produced here need not be contiguous in the source: all elements sharing
+/// a key end up in the same group no matter where they occur.
+///
+/// See [`.into_grouping_map()`](trait.Itertools.html#method.into_grouping_map) and
+/// [`.into_grouping_map_by()`](trait.Itertools.html#method.into_grouping_map_by)
+/// for more information.
+pub struct GroupingMap<I> {
+    iter: I,
+}
+
+impl<I, K, V> GroupingMap<I>
+    where I: Iterator<Item = (K, V)>,
+          K: Hash + Eq,
+{
+    /// This is the generic way to perform any operation on a `GroupingMap`.
+    /// It's suggested to use this method only to implement custom operations
+    /// when the already-provided ones are not enough.
+    ///
+    /// Groups elements from the source by key and applies `operation` to the
+    /// elements of each group in turn, passing the accumulator built so far
+    /// (or `None` for the first element of a group), a reference to the key,
+    /// and the element itself.
+    ///
+    /// If `operation` returns `Some(acc)` the accumulator for that key is
+    /// updated to `acc`; if it returns `None`, the key is removed from the
+    /// result (or simply never inserted). A later element with the same key
+    /// is then passed `None` again and can start a new accumulator.
+    pub fn aggregate<FO, R>(self, mut operation: FO) -> HashMap<K, R>
+        where FO: FnMut(Option<R>, &K, V) -> Option<R>,
+    {
+        // Store `Option<R>` slots so each element only needs a single
+        // `entry()` lookup: the accumulator is taken out of its slot in
+        // place (no need to remove and later re-insert the entry).
+        let mut slots: HashMap<K, Option<R>> = HashMap::new();
+
+        for (key, val) in self.iter {
+            match slots.entry(key) {
+                Entry::Occupied(mut entry) => {
+                    let acc = entry.get_mut().take();
+                    let result = operation(acc, entry.key(), val);
+                    *entry.get_mut() = result;
+                }
+                Entry::Vacant(entry) => {
+                    let result = operation(None, entry.key(), val);
+                    entry.insert(result);
+                }
+            }
+        }
+
+        slots.into_iter().filter_map(|(k, v)| v.map(|v| (k, v))).collect()
+    }
+
+    /// Groups elements from the source by key and folds the elements of each
+    /// group using `init` as the starting accumulator and `fold` to combine
+    /// it with each element.
+    pub fn fold<FO, R>(self, init: R, mut fold: FO) -> HashMap<K, R>
+        where R: Clone,
+              FO: FnMut(R, &K, V) -> R,
+    {
+        self.aggregate(|acc, key, val| {
+            Some(fold(acc.unwrap_or_else(|| init.clone()), key, val))
+        })
+    }
+
+    /// Groups elements from the source by key and folds the elements of each
+    /// group using the group's first element as the initial accumulator.
+    pub fn fold_first<FO>(self, mut fold: FO) -> HashMap<K, V>
+        where FO: FnMut(V, &K, V) -> V,
+    {
+        self.aggregate(|acc, key, val| {
+            Some(match acc {
+                Some(acc) => fold(acc, key, val),
+                None => val,
+            })
+        })
+    }
+
+    /// Groups elements from the source by key and reduces the elements of
+    /// each group using `op`.
+    ///
+    /// This is a convenience method equivalent to [`.fold_first()`](GroupingMap::fold_first).
+    pub fn reduce<FO>(self, op: FO) -> HashMap<K, V>
+        where FO: FnMut(V, &K, V) -> V,
+    {
+        self.fold_first(op)
+    }
+
+    /// Groups elements from the source by key and finds the maximum of each
+    /// group.
+    pub fn max(self) -> HashMap<K, V>
+        where V: Ord,
+    {
+        self.max_by(|_, v1, v2| V::cmp(v1, v2))
+    }
+
+    /// Groups elements from the source by key and finds the maximum of each
+    /// group with respect to the given comparison function.
+    pub fn max_by<F>(self, mut compare: F) -> HashMap<K, V>
+        where F: FnMut(&K, &V, &V) -> Ordering,
+    {
+        self.fold_first(|acc, key, val| {
+            match compare(key, &acc, &val) {
+                Ordering::Less | Ordering::Equal => val,
+                Ordering::Greater => acc,
+            }
+        })
+    }
+
+    /// Groups elements from the source by key and finds the element of each
+    /// group that gives the maximum value from the given function.
+    pub fn max_by_key<F, CK>(self, mut f: F) -> HashMap<K, V>
+        where F: FnMut(&K, &V) -> CK,
+              CK: Ord,
+    {
+        self.max_by(|key, v1, v2| f(key, v1).cmp(&f(key, v2)))
+    }
+
+    /// Groups elements from the source by key and finds the minimum of each
+    /// group.
+    pub fn min(self) -> HashMap<K, V>
+        where V: Ord,
+    {
+        self.min_by(|_, v1, v2| V::cmp(v1, v2))
+    }
+
+    /// Groups elements from the source by key and finds the minimum of each
+    /// group with respect to the given comparison function.
+    pub fn min_by<F>(self, mut compare: F) -> HashMap<K, V>
+        where F: FnMut(&K, &V, &V) -> Ordering,
+    {
+        self.fold_first(|acc, key, val| {
+            match compare(key, &acc, &val) {
+                Ordering::Less | Ordering::Equal => acc,
+                Ordering::Greater => val,
+            }
+        })
+    }
+
+    /// Groups elements from the source by key and finds the element of each
+    /// group that gives the minimum value from the given function.
+    pub fn min_by_key<F, CK>(self, mut f: F) -> HashMap<K, V>
+        where F: FnMut(&K, &V) -> CK,
+              CK: Ord,
+    {
+        self.min_by(|key, v1, v2| f(key, v1).cmp(&f(key, v2)))
+    }
+
+    /// Groups elements from the source by key and sums them within each
+    /// group.
+    pub fn sum(self) -> HashMap<K, V>
+        where V: Add<V, Output = V>,
+    {
+        self.fold_first(|acc, _, val| acc + val)
+    }
+
+    /// Groups elements from the source by key and multiplies them together
+    /// within each group.
+    pub fn product(self) -> HashMap<K, V>
+        where V: Mul<V, Output = V>,
+    {
+        self.fold_first(|acc, _, val| acc * val)
+    }
+
+    /// Groups elements from the source by key and counts the number of
+    /// elements in each group.
+    pub fn count(self) -> HashMap<K, usize> {
+        self.fold(0, |acc, _, _| acc + 1)
+    }
+
+    /// Groups elements from the source by key and collects the elements of
+    /// each group into a `C`.
+    pub fn collect<C>(self) -> HashMap<K, C>
+        where C: Default + Extend<V>,
+    {
+        self.aggregate(|acc, _, val| {
+            let mut acc = acc.unwrap_or_else(C::default);
+            acc.extend(Some(val));
+            Some(acc)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fold_sums_each_group() {
+        let data = vec![(1, 10), (2, 20), (1, 5), (2, 1), (3, 9)];
+        let result = new(data.into_iter()).fold(0, |acc, _, v| acc + v);
+        assert_eq!(result.get(&1), Some(&15));
+        assert_eq!(result.get(&2), Some(&21));
+        assert_eq!(result.get(&3), Some(&9));
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn min_and_max_per_group() {
+        let data = vec![(1, 3), (1, 7), (1, 1), (2, 4)];
+        let mins = new(data.clone().into_iter()).min();
+        let maxes = new(data.into_iter()).max();
+        assert_eq!(mins.get(&1), Some(&1));
+        assert_eq!(maxes.get(&1), Some(&7));
+        assert_eq!(mins.get(&2), Some(&4));
+    }
+
+    #[test]
+    fn count_and_collect() {
+        let data = vec![(1, 'a'), (1, 'b'), (2, 'c')];
+        let counts = new(data.clone().into_iter()).count();
+        assert_eq!(counts.get(&1), Some(&2));
+        assert_eq!(counts.get(&2), Some(&1));
+
+        let collected = new(data.into_iter()).collect::<Vec<_>>();
+        assert_eq!(collected.get(&1), Some(&vec!['a', 'b']));
+        assert_eq!(collected.get(&2), Some(&vec!['c']));
+    }
+
+    #[test]
+    fn into_grouping_map_by_keys_on_parity() {
+        let grouped = new_by(0..10, |v: &i32| v % 2).sum();
+        assert_eq!(grouped.get(&0), Some(&20)); // 0+2+4+6+8
+        assert_eq!(grouped.get(&1), Some(&25)); // 1+3+5+7+9
+    }
+
+    #[test]
+    fn aggregate_can_delete_keys() {
+        let data = vec![(1, 1), (1, 2), (1, 3), (2, 1), (2, 2)];
+        // drop a key's accumulator entirely once its running sum exceeds 3
+        let result = new(data.into_iter()).aggregate(|acc, _, val| {
+            let sum = acc.unwrap_or(0) + val;
+            if sum > 3 { None } else { Some(sum) }
+        });
+        assert_eq!(result.get(&1), None);
+        assert_eq!(result.get(&2), Some(&3));
+    }
+}